@@ -0,0 +1,57 @@
+use std::{hint::black_box, time::Instant};
+
+use bones_ecs::prelude::*;
+
+// Define a few resource types, to read several resources per frame like a game system would.
+
+#[derive(Clone, Debug, Default, TypeUlid)]
+#[ulid = "01H1RB4XK7N2Q9V3C5T8WJ6MDE"]
+pub struct Gravity(f32);
+
+#[derive(Clone, Debug, Default, TypeUlid)]
+#[ulid = "01H1RB5C2E8H4M7R1X9PZ3KVQT"]
+pub struct Score(u64);
+
+#[derive(Clone, Debug, Default, TypeUlid)]
+#[ulid = "01H1RB5N6W3J9D2F8A4YS7GBCX"]
+pub struct FrameCount(u64);
+
+/// The number of simulated frames to time each access path over.
+const FRAMES: u32 = 1_000_000;
+
+fn main() {
+    let mut resources = Resources::new();
+    resources.insert(Gravity(9.8));
+    resources.insert(Score(10));
+    resources.insert(FrameCount(0));
+
+    // Access through a resource handle, which clones the handle's `Arc` for every resource.
+    let start = Instant::now();
+    for _ in 0..FRAMES {
+        black_box(resources.get::<Gravity>().borrow().0);
+        black_box(resources.get::<Score>().borrow().0);
+        resources.get::<FrameCount>().borrow_mut().0 += 1;
+    }
+    let handle_time = start.elapsed();
+
+    // Access by borrowing directly from the store, which doesn't touch the `Arc`.
+    let start = Instant::now();
+    for _ in 0..FRAMES {
+        black_box(resources.get_ref::<Gravity>().0);
+        black_box(resources.get_ref::<Score>().0);
+        resources.get_ref_mut::<FrameCount>().0 += 1;
+    }
+    let ref_time = start.elapsed();
+
+    assert_eq!(resources.get_ref::<FrameCount>().0, 2 * FRAMES as u64);
+
+    println!("Accessing 3 resources per frame over {FRAMES} frames:");
+    println!(
+        "  get().borrow(): {handle_time:?} total, {:?} per frame",
+        handle_time / FRAMES
+    );
+    println!(
+        "  get_ref():      {ref_time:?} total, {:?} per frame",
+        ref_time / FRAMES
+    );
+}
@@ -28,9 +28,10 @@ pub struct UntypedResourceInfo {
     /// The memory layout of the resource
     pub layout: Layout,
     /// Cell containing the raw pointer to the resource's data
-    // TODO: Evaluate possibility of avoiding an `Arc` clone.
-    // We might be able to just just pass references with a lifetime for acessing it,
-    // instead of cloning the Arc like we do here.
+    // TODO: Evaluate possibility of avoiding an `Arc` clone in `Res` and `ResMut`.
+    // `Resources::get_ref()` borrows the cell without cloning the `Arc`, but the `Res` and `ResMut`
+    // system params still clone it every run, because `SystemParam::State` can't borrow from the
+    // world.
     pub cell: Arc<AtomicRefCell<*mut u8>>,
     /// A function that may be called to clone the resource from one pointer to another.
     pub clone_fn: unsafe extern "C" fn(*const u8, *mut u8),
@@ -163,6 +164,14 @@ impl UntypedResources {
         self.resources.get(&uuid).map(|x| x.cell.clone())
     }
 
+    /// Get a reference to the cell containing the resource data pointer for the given ID.
+    ///
+    /// Unlike [`get()`][Self::get], this borrows the cell from the store instead of cloning its
+    /// [`Arc`].
+    pub fn get_cell(&self, uuid: Ulid) -> Option<&AtomicRefCell<*mut u8>> {
+        self.resources.get(&uuid).map(|x| &*x.cell)
+    }
+
     /// Remove a resource
     pub fn remove(&mut self, uuid: Ulid) -> Option<UntypedResource> {
         self.resources.remove(&uuid)
@@ -254,6 +263,64 @@ impl Resources {
         })
     }
 
+    /// Lock a resource in the store for reading.
+    ///
+    /// Unlike [`get()`][Self::get], this doesn't create a handle to the resource, it borrows it
+    /// directly from the store. This avoids cloning the handle's [`Arc`], but the returned guard can't
+    /// outlive the [`Resources`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resource does not exist in the store, or if it is already borrowed mutably.
+    #[track_caller]
+    pub fn get_ref<T: TypedEcsData>(&self) -> AtomicRef<T> {
+        self.try_get_ref().unwrap()
+    }
+
+    /// Lock a resource in the store for reading, if it exists.
+    ///
+    /// See [`get_ref()`][Self::get_ref].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resource is already borrowed mutably.
+    #[track_caller]
+    pub fn try_get_ref<T: TypedEcsData>(&self) -> Option<AtomicRef<T>> {
+        let cell = self.untyped.get_cell(T::ULID)?;
+        // SAFE: We know that the data pointer is valid for type T.
+        Some(AtomicRef::map(cell.borrow(), |data| unsafe {
+            &*data.cast::<T>()
+        }))
+    }
+
+    /// Lock a resource in the store for read-writing.
+    ///
+    /// See [`get_ref()`][Self::get_ref].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resource does not exist in the store, or if it is already borrowed.
+    #[track_caller]
+    pub fn get_ref_mut<T: TypedEcsData>(&self) -> AtomicRefMut<T> {
+        self.try_get_ref_mut().unwrap()
+    }
+
+    /// Lock a resource in the store for read-writing, if it exists.
+    ///
+    /// See [`get_ref()`][Self::get_ref].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resource is already borrowed.
+    #[track_caller]
+    pub fn try_get_ref_mut<T: TypedEcsData>(&self) -> Option<AtomicRefMut<T>> {
+        let cell = self.untyped.get_cell(T::ULID)?;
+        // SAFE: We know that the data pointer is valid for type T.
+        Some(AtomicRefMut::map(cell.borrow_mut(), |data| unsafe {
+            &mut *data.cast::<T>()
+        }))
+    }
+
     /// Borrow the underlying [`UntypedResources`] store.
     pub fn untyped(&self) -> &UntypedResources {
         &self.untyped
@@ -333,4 +400,42 @@ mod test {
         assert_eq!(resources.get::<B>().borrow().0, 2);
         assert_eq!(resources.get::<A>().borrow().0, vec![7, 8, 9]);
     }
+
+    #[test]
+    fn borrow_without_handle() {
+        #[derive(TypeUlid, Clone, Debug)]
+        #[ulid = "01H1QGMK3T6Y4D5V0E8XQ2Z7WN"]
+        struct A(u32);
+
+        let mut resources = Resources::new();
+        assert!(resources.try_get_ref::<A>().is_none());
+        assert!(resources.try_get_ref_mut::<A>().is_none());
+
+        resources.insert(A(1));
+
+        resources.get_ref_mut::<A>().0 = 2;
+        assert_eq!(resources.get_ref::<A>().0, 2);
+
+        // The borrow and the handle lock the same resource.
+        {
+            let a = resources.get_ref::<A>();
+            assert_eq!(resources.get::<A>().borrow().0, a.0);
+        }
+        resources.get::<A>().borrow_mut().0 = 3;
+        assert_eq!(resources.get_ref::<A>().0, 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "already immutably borrowed")]
+    fn borrow_without_handle_conflict() {
+        #[derive(TypeUlid, Clone, Debug)]
+        #[ulid = "01H1RD2F5Q8T3X7B4M9KWN6JCE"]
+        struct A(u32);
+
+        let mut resources = Resources::new();
+        resources.insert(A(1));
+
+        let _a = resources.get_ref::<A>();
+        resources.get_ref_mut::<A>().0 = 2;
+    }
 }
@@ -120,21 +120,53 @@ impl World {
     /// # Panics
     ///
     /// Panics if the resource does not exist in the store.
+    #[track_caller]
     pub fn resource<R: TypedEcsData>(&self) -> AtomicResource<R> {
-        match self.resources.try_get::<R>() {
-            Some(r) => r,
-            None => panic!(
-                "Requested resource {} does not exist in the `World`.
-                Did you forget to add it using `world.insert_resource` / `world.init_resource`?",
-                std::any::type_name::<R>()
-            ),
-        }
+        expect_resource::<R, _>(self.resources.try_get::<R>())
     }
 
     /// Gets a resource handle from the store if it exists.
     pub fn get_resource<R: TypedEcsData>(&self) -> Option<AtomicResource<R>> {
         self.resources.try_get::<R>()
     }
+
+    /// Lock a resource in the world for reading, without creating a handle to it.
+    ///
+    /// This is cheaper than [`resource()`][Self::resource] when you only need to access the
+    /// resource while you hold the [`World`] reference.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resource does not exist in the `World`, or if it is already borrowed mutably.
+    #[track_caller]
+    pub fn resource_ref<R: TypedEcsData>(&self) -> AtomicRef<R> {
+        expect_resource::<R, _>(self.resources.try_get_ref::<R>())
+    }
+
+    /// Lock a resource in the world for read-writing, without creating a handle to it.
+    ///
+    /// See [`resource_ref()`][Self::resource_ref].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resource does not exist in the `World`, or if it is already borrowed.
+    #[track_caller]
+    pub fn resource_ref_mut<R: TypedEcsData>(&self) -> AtomicRefMut<R> {
+        expect_resource::<R, _>(self.resources.try_get_ref_mut::<R>())
+    }
+}
+
+/// Unwraps a resource accessed from the world, panicking with a helpful message if it was missing.
+#[track_caller]
+fn expect_resource<R, T>(resource: Option<T>) -> T {
+    match resource {
+        Some(r) => r,
+        None => panic!(
+            "Requested resource {} does not exist in the `World`.
+            Did you forget to add it using `world.insert_resource` / `world.init_resource`?",
+            std::any::type_name::<R>()
+        ),
+    }
 }
 
 /// Creates an instance of the type this trait is implemented for
@@ -303,6 +335,42 @@ mod tests {
 
     fn send<T: Send>(_: T) {}
 
+    #[test]
+    fn resource_ref() {
+        #[derive(Clone, TypeUlid)]
+        #[ulid = "01H1RC8T4G6N2W9K3P7XQ5MZDA"]
+        struct A(u32);
+
+        let mut w = World::default();
+        w.insert_resource(A(1));
+
+        w.resource_ref_mut::<A>().0 = 2;
+        assert_eq!(w.resource_ref::<A>().0, 2);
+        assert_eq!(w.resource::<A>().borrow().0, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "does not exist in the `World`")]
+    fn resource_ref_missing() {
+        #[derive(Clone, TypeUlid)]
+        #[ulid = "01H1RC9B7E3V5J8R2N6TYQ4HWS"]
+        struct A;
+
+        let w = World::default();
+        w.resource_ref::<A>();
+    }
+
+    #[test]
+    #[should_panic(expected = "does not exist in the `World`")]
+    fn resource_ref_mut_missing() {
+        #[derive(Clone, TypeUlid)]
+        #[ulid = "01H1RC9M2K8D4X6F9B3ZCQ7NVH"]
+        struct A;
+
+        let w = World::default();
+        w.resource_ref_mut::<A>();
+    }
+
     // ============
     //  From World
     // ============